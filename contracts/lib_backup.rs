@@ -1,3 +1,7 @@
+mod models;
+
+use models::{Page, SemanticGuardRecord};
+use near_sdk::collections::Vector;
 use near_sdk::near;
 use std::collections::HashMap;
 
@@ -5,8 +9,12 @@ use std::collections::HashMap;
 pub struct NearGravitySemanticGuard {
     owner: String,
     total_analyses: u64,
-    // Store analysis data as JSON strings for simplicity 
-    analyses: HashMap<String, String>,
+    // Analyses keyed by id, stored as the same typed `SemanticGuardRecord`
+    // the other semantic-guard contracts use, rather than an opaque JSON string.
+    analyses: HashMap<String, SemanticGuardRecord>,
+    // Analysis ids in insertion order, so queries can page without
+    // collecting and sorting every key in `analyses`
+    analysis_ids: Vector<String>,
 }
 
 impl Default for NearGravitySemanticGuard {
@@ -15,6 +23,7 @@ impl Default for NearGravitySemanticGuard {
             owner: "NearGravity.near".to_string(),
             total_analyses: 0,
             analyses: HashMap::new(),
+            analysis_ids: Vector::new(b"i"),
         }
     }
 }
@@ -27,6 +36,7 @@ impl NearGravitySemanticGuard {
             owner,
             total_analyses: 0,
             analyses: HashMap::new(),
+            analysis_ids: Vector::new(b"i"),
         }
     }
 
@@ -37,6 +47,7 @@ impl NearGravitySemanticGuard {
             owner: "NearGravity.near".to_string(),
             total_analyses: 0,
             analyses: HashMap::new(),
+            analysis_ids: Vector::new(b"i"),
         }
     }
 
@@ -51,48 +62,75 @@ impl NearGravitySemanticGuard {
         stats
     }
 
-    pub fn store_semantic_analysis(&mut self, analysis_id: String, analysis_json: String) -> String {
+    pub fn store_semantic_analysis(&mut self, analysis_id: String, record: SemanticGuardRecord) -> String {
         if analysis_id.is_empty() {
             near_sdk::env::panic_str("Analysis ID cannot be empty");
         }
 
-        if analysis_json.is_empty() {
-            near_sdk::env::panic_str("Analysis JSON cannot be empty");
-        }
-
         self.total_analyses += 1;
-        self.analyses.insert(analysis_id.clone(), analysis_json);
+        self.analyses.insert(analysis_id.clone(), record);
+        self.analysis_ids.push(&analysis_id);
 
         near_sdk::env::log_str(&format!(
-            "Stored semantic analysis: {} (total: {})", 
-            analysis_id, 
+            "Stored semantic analysis: {} (total: {})",
+            analysis_id,
             self.total_analyses
         ));
 
         format!("analysis_{}", self.total_analyses)
     }
 
-    pub fn get_semantic_analysis(&self, analysis_id: String) -> Option<String> {
+    pub fn get_semantic_analysis(&self, analysis_id: String) -> Option<SemanticGuardRecord> {
         self.analyses.get(&analysis_id).cloned()
     }
 
-    pub fn search_by_query(&self, query: String) -> Vec<String> {
+    /// Page over ids whose query text matches, scanning only the
+    /// `[from_index, from_index + limit)` window of `analysis_ids` per call
+    /// instead of the whole collection.
+    pub fn search_by_query(&self, query: String, from_index: u64, limit: u64) -> Page<String> {
         let query_lower = query.to_lowercase();
-        self.analyses
-            .iter()
-            .filter(|(_, data)| data.to_lowercase().contains(&query_lower))
-            .map(|(id, _)| id.clone())
-            .collect()
+        let total = self.analysis_ids.len();
+        let end = (from_index + limit).min(total);
+
+        let items = if from_index < end {
+            (from_index..end)
+                .filter_map(|i| self.analysis_ids.get(i))
+                .filter(|id| {
+                    self.analyses
+                        .get(id)
+                        .map(|record| record.query.to_lowercase().contains(&query_lower))
+                        .unwrap_or(false)
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        Page {
+            items,
+            next_index: end,
+            total,
+        }
     }
 
-    pub fn get_recent_analyses(&self, limit: u32) -> Vec<String> {
-        // Return most recent analysis IDs (simplified implementation)
-        let mut ids: Vec<String> = self.analyses.keys().cloned().collect();
-        ids.sort();
-        ids.into_iter()
-            .rev()
-            .take(limit as usize)
-            .collect()
+    /// Page over the most recently stored analysis ids, newest first.
+    pub fn get_recent_analyses(&self, from_index: u64, limit: u64) -> Page<String> {
+        let total = self.analysis_ids.len();
+        let mut items = Vec::new();
+        let mut i = from_index;
+        while i < total && (items.len() as u64) < limit {
+            // `from_index` counts back from the newest id.
+            if let Some(id) = self.analysis_ids.get(total - 1 - i) {
+                items.push(id);
+            }
+            i += 1;
+        }
+
+        Page {
+            items,
+            next_index: i,
+            total,
+        }
     }
 
     pub fn increment(&mut self) -> u64 {
@@ -103,4 +141,4 @@ impl NearGravitySemanticGuard {
     pub fn delete_analysis(&mut self, analysis_id: String) -> bool {
         self.analyses.remove(&analysis_id).is_some()
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,140 @@
+/*
+ * Shared data-transfer objects for NearGravity's semantic-guard contracts.
+ *
+ * Every contract in this directory used to hand-roll its own
+ * `#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]`
+ * plus `#[serde(crate = "near_sdk::serde")]` on each record, and defined its
+ * own slightly-divergent copy of the same shapes. This module is the single
+ * source of truth: one `#[near(serializers = [json, borsh])]` attribute gets
+ * both serialization formats plus the schema derive `cargo near abi` needs to
+ * emit a complete JSON ABI describing every public method's inputs and these
+ * record shapes, so off-chain TypeScript/Python clients can codegen types
+ * instead of guessing field names.
+ */
+
+use near_sdk::near;
+use near_sdk::AccountId;
+use std::collections::HashMap;
+
+/// Mirrors src/models/dto/rag_models.rs::SemanticDelta
+#[near(serializers = [json, borsh])]
+#[derive(Clone, Debug)]
+pub struct SemanticDelta {
+    pub similarity_score: f64,
+    pub confidence_level: f64,
+    pub transformation_type: String,
+    pub semantic_distance: f64,
+    pub integrity_verified: bool,
+}
+
+/// Mirrors src/models/dto/rag_models.rs::MessageEmbedding
+#[near(serializers = [json, borsh])]
+#[derive(Clone, Debug)]
+pub struct MessageEmbedding {
+    pub vector: Vec<f64>,
+    pub model_name: String,
+    pub embedding_hash: String,
+    pub semantic_hash: String,
+    pub timestamp: u64,
+}
+
+/// Mirrors src/services/ai/semantic_service.rs patterns
+#[near(serializers = [json, borsh])]
+#[derive(Clone, Debug)]
+pub struct SemanticAnalysisResult {
+    pub center_of_gravity: String,
+    pub outliers: Vec<SemanticOutlier>,
+    pub distance_matrix: HashMap<String, f64>,
+    pub embeddings: Vec<MessageEmbedding>,
+    pub threshold_used: f64,
+    pub processing_time_ms: u64,
+    pub consensus_score: f64,
+}
+
+/// Extends NearGravity's outlier detection with blockchain verification
+#[near(serializers = [json, borsh])]
+#[derive(Clone, Debug)]
+pub struct SemanticOutlier {
+    pub result_id: String,
+    pub reason: String,
+    pub severity: OutlierSeverity,
+    pub max_distance: f64,
+    pub source_type: String,
+    pub verification_status: VerificationStatus,
+    pub outlier_distances: Vec<OutlierDistance>,
+}
+
+#[near(serializers = [json, borsh])]
+#[derive(Clone, Debug)]
+pub struct OutlierDistance {
+    pub to_result: String,
+    pub distance: f64,
+    pub threshold_exceeded_by: f64,
+}
+
+#[near(serializers = [json, borsh])]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum OutlierSeverity {
+    Low,
+    Medium,
+    High,
+    Critical,
+}
+
+#[near(serializers = [json, borsh])]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum VerificationStatus {
+    Pending,
+    Verified,
+    Disputed,
+    Flagged,
+}
+
+/// Search result with semantic metadata
+#[near(serializers = [json, borsh])]
+#[derive(Clone, Debug)]
+pub struct SearchResult {
+    pub id: String,
+    pub title: String,
+    pub snippet: String,
+    pub url: String,
+    pub rank: u32,
+    pub source_type: String,
+    pub semantic_hash: String,
+    pub trustworthiness_score: f64,
+}
+
+/// Complete semantic guard analysis record
+#[near(serializers = [json, borsh])]
+#[derive(Clone, Debug)]
+pub struct SemanticGuardRecord {
+    pub id: String,
+    pub query: String,
+    pub results: Vec<SearchResult>,
+    pub semantic_analysis: SemanticAnalysisResult,
+    pub submitter: AccountId,
+    pub timestamp: u64,
+    pub block_height: u64,
+    pub metadata: AnalysisMetadata,
+}
+
+#[near(serializers = [json, borsh])]
+#[derive(Clone, Debug)]
+pub struct AnalysisMetadata {
+    pub model_version: String,
+    pub algorithm_version: String,
+    pub processing_node: String,
+    pub verification_count: u32,
+    pub dispute_count: u32,
+    pub consensus_reached: bool,
+}
+
+/// A page of results from a paginated query, with enough state for a client
+/// to request the next page.
+#[near(serializers = [json, borsh])]
+#[derive(Clone, Debug)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub next_index: u64,
+    pub total: u64,
+}
@@ -11,128 +11,241 @@
  * - src/hack/ hackathon package
  */
 
+mod models;
+
 use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
-use near_sdk::collections::{LookupMap, UnorderedMap};
-use near_sdk::serde::{Deserialize, Serialize};
-use near_sdk::{env, near_bindgen, AccountId, PanicOnDefault, Promise, CryptoHash};
+use near_sdk::collections::{LookupMap, UnorderedMap, Vector};
+use near_sdk::near;
+use near_sdk::{env, near_bindgen, AccountId, NearToken, PanicOnDefault, Promise};
 use std::collections::HashMap;
 
 // ============================================================================
 // NEARGRAVITY DTO INTEGRATION
 // ============================================================================
-// These types mirror the data structures from NearGravity's core system
-
-/// Mirrors src/models/dto/rag_models.rs::SemanticDelta
-#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
-#[serde(crate = "near_sdk::serde")]
-pub struct SemanticDelta {
-    pub similarity_score: f64,
-    pub confidence_level: f64,
-    pub transformation_type: String,
-    pub semantic_distance: f64,
-    pub integrity_verified: bool,
+// The record shapes shared across NearGravity's contracts (SemanticDelta,
+// MessageEmbedding, SemanticAnalysisResult, SemanticOutlier, SearchResult,
+// SemanticGuardRecord, ...) now live in `models`, each carrying
+// `#[near(serializers = [json, borsh])]` so `cargo near abi` can emit a
+// complete schema for every public method and record shape below.
+
+pub use models::*;
+
+/// Result of recomputing an analysis' semantic geometry from stored embeddings
+/// and reconciling it against what the submitter originally reported.
+#[near(serializers = [json, borsh])]
+#[derive(Clone, Debug)]
+pub struct VerificationReport {
+    pub analysis_id: String,
+    pub verified_count: u32,
+    pub flagged_count: u32,
+    /// Result ids the submitter omitted but the contract independently flagged
+    pub newly_flagged: Vec<String>,
+    pub recomputed_center_of_gravity: String,
+    pub center_of_gravity_changed: bool,
+    pub consensus_score: f64,
+    /// Human-readable descriptions of every discrepancy found during recomputation
+    pub mismatches: Vec<String>,
 }
 
-/// Mirrors src/models/dto/rag_models.rs::MessageEmbedding
-#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
-#[serde(crate = "near_sdk::serde")]
-pub struct MessageEmbedding {
-    pub vector: Vec<f64>,
-    pub model_name: String,
-    pub embedding_hash: String,
-    pub semantic_hash: String,
-    pub timestamp: u64,
+/// Count of outliers at each severity level, carried in a published verdict
+/// so a verifier on another chain can judge risk without the full record.
+#[near(serializers = [json, borsh])]
+#[derive(Clone, Debug)]
+pub struct SeveritySummary {
+    pub low: u32,
+    pub medium: u32,
+    pub high: u32,
+    pub critical: u32,
 }
 
-/// Mirrors src/services/ai/semantic_service.rs patterns
-#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
-#[serde(crate = "near_sdk::serde")]
-pub struct SemanticAnalysisResult {
+/// Compact, portable representation of a consensus-reached analysis, Borsh-encoded
+/// into a deterministic byte message for cross-chain publication.
+#[near(serializers = [json, borsh])]
+#[derive(Clone, Debug)]
+pub struct VerdictPayload {
+    pub analysis_id: String,
+    pub semantic_hashes: Vec<String>,
     pub center_of_gravity: String,
-    pub outliers: Vec<SemanticOutlier>,
-    pub distance_matrix: HashMap<String, f64>,
-    pub embeddings: Vec<MessageEmbedding>,
-    pub threshold_used: f64,
-    pub processing_time_ms: u64,
     pub consensus_score: f64,
+    pub severity_summary: SeveritySummary,
 }
 
-/// Extends NearGravity's outlier detection with blockchain verification
-#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
-#[serde(crate = "near_sdk::serde")]
-pub struct SemanticOutlier {
-    pub result_id: String,
-    pub reason: String,
-    pub severity: OutlierSeverity,
-    pub max_distance: f64,
-    pub source_type: String,
-    pub verification_status: VerificationStatus,
-    pub outlier_distances: Vec<OutlierDistance>,
+/// Implemented by whatever off-chain transport relays a published verdict
+/// (a Wormhole-style guardian set, a light-client relayer, ...). The contract
+/// only needs to hand the transport a sequence number and the encoded
+/// payload; swapping transports means swapping the implementation that
+/// `publish_verdict` calls.
+pub trait MessageEmitter {
+    fn emit(&self, sequence: u64, chain_id: u32, payload: &[u8]);
 }
 
-#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
-#[serde(crate = "near_sdk::serde")]
-pub struct OutlierDistance {
-    pub to_result: String,
-    pub distance: f64,
-    pub threshold_exceeded_by: f64,
+/// Default emitter: surfaces the message as a structured `env::log_str` event
+/// that an off-chain relayer/guardian can observe and attest.
+pub struct LogMessageEmitter;
+
+impl MessageEmitter for LogMessageEmitter {
+    fn emit(&self, sequence: u64, chain_id: u32, payload: &[u8]) {
+        env::log_str(&format!(
+            "EVENT_JSON:{{\"standard\":\"semanticguard\",\"version\":\"1.0.0\",\"event\":\"publish_verdict\",\"data\":{{\"sequence\":{},\"chain_id\":{},\"payload_hex\":\"{}\"}}}}",
+            sequence,
+            chain_id,
+            hex_encode(payload)
+        ));
+    }
 }
 
-#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
-#[serde(crate = "near_sdk::serde")]
-pub enum OutlierSeverity {
-    Low,
-    Medium,
-    High,
-    Critical,
+/// Owner-configurable target for cross-chain publication.
+#[near(serializers = [json, borsh])]
+#[derive(Clone, Debug)]
+pub struct BridgeConfig {
+    pub owner_id: AccountId,
+    pub target_chain_id: u32,
 }
 
-#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
-#[serde(crate = "near_sdk::serde")]
-pub enum VerificationStatus {
-    Pending,
-    Verified,
-    Disputed,
-    Flagged,
+/// Upper bound on embeddings recomputed in a single `verify_analysis` call, so the
+/// O(n^2) pairwise distance rebuild stays within a predictable gas budget.
+const MAX_VERIFICATION_VECTORS: usize = 200;
+
+/// Cosine distance `1 - (a·b)/(‖a‖‖b‖)`. Zero-length or zero-norm vectors are
+/// treated as maximally distant (2.0, the max of the cosine-distance range)
+/// rather than dividing by zero.
+fn cosine_distance(a: &[f64], b: &[f64]) -> f64 {
+    if a.is_empty() || b.is_empty() {
+        return 2.0;
+    }
+    let norm_a = a.iter().map(|x| x * x).sum::<f64>().sqrt();
+    let norm_b = b.iter().map(|x| x * x).sum::<f64>().sqrt();
+    if norm_a == 0.0 || norm_b == 0.0 {
+        return 2.0;
+    }
+    let dot: f64 = a.iter().zip(b.iter()).map(|(x, y)| x * y).sum();
+    1.0 - (dot / (norm_a * norm_b))
 }
 
-/// Search result with semantic metadata
-#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
-#[serde(crate = "near_sdk::serde")]
-pub struct SearchResult {
-    pub id: String,
-    pub title: String,
-    pub snippet: String,
-    pub url: String,
-    pub rank: u32,
-    pub source_type: String,
-    pub semantic_hash: String,
-    pub trustworthiness_score: f64,
+/// Canonical, order-independent key for a pair of result ids in the distance matrix.
+fn distance_matrix_key(a: &str, b: &str) -> String {
+    if a <= b {
+        format!("{}::{}", a, b)
+    } else {
+        format!("{}::{}", b, a)
+    }
 }
 
-/// Complete semantic guard analysis record
-#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
-#[serde(crate = "near_sdk::serde")]
-pub struct SemanticGuardRecord {
-    pub id: String,
-    pub query: String,
-    pub results: Vec<SearchResult>,
-    pub semantic_analysis: SemanticAnalysisResult,
-    pub submitter: AccountId,
-    pub timestamp: u64,
-    pub block_height: u64,
-    pub metadata: AnalysisMetadata,
+/// Tallies outliers by severity for a compact cross-chain summary.
+fn summarize_severity(outliers: &[SemanticOutlier]) -> SeveritySummary {
+    let mut summary = SeveritySummary {
+        low: 0,
+        medium: 0,
+        high: 0,
+        critical: 0,
+    };
+    for outlier in outliers {
+        match outlier.severity {
+            OutlierSeverity::Low => summary.low += 1,
+            OutlierSeverity::Medium => summary.medium += 1,
+            OutlierSeverity::High => summary.high += 1,
+            OutlierSeverity::Critical => summary.critical += 1,
+        }
+    }
+    summary
+}
+
+/// Lowercase hex encoding, used to carry an opaque byte payload inside a log event.
+fn hex_encode(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Storage key for a single account's stake position on an analysis.
+fn stake_key(analysis_id: &str, account_id: &AccountId) -> String {
+    format!("{}:{}", analysis_id, account_id)
+}
+
+/// `a * b` widened into a 256-bit product, returned as (high, low) 128-bit halves.
+fn widening_mul_u128(a: u128, b: u128) -> (u128, u128) {
+    let mask = u64::MAX as u128;
+    let (a_lo, a_hi) = (a & mask, a >> 64);
+    let (b_lo, b_hi) = (b & mask, b >> 64);
+
+    let lo_lo = a_lo * b_lo;
+    let lo_hi = a_lo * b_hi;
+    let hi_lo = a_hi * b_lo;
+    let hi_hi = a_hi * b_hi;
+
+    let mid = (lo_lo >> 64) + (lo_hi & mask) + (hi_lo & mask);
+    let low = (lo_lo & mask) | ((mid & mask) << 64);
+    let high = hi_hi + (lo_hi >> 64) + (hi_lo >> 64) + (mid >> 64);
+
+    (high, low)
 }
 
-#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
-#[serde(crate = "near_sdk::serde")]
-pub struct AnalysisMetadata {
-    pub model_version: String,
-    pub algorithm_version: String,
-    pub processing_node: String,
-    pub verification_count: u32,
-    pub dispute_count: u32,
-    pub consensus_reached: bool,
+/// `(a * b) / c` for u128 operands, computed via a widened 256-bit
+/// intermediate product instead of a bare `a * b` that can overflow
+/// `u128` long before the division brings the result back into range.
+/// Panics if `c` is zero or if the true quotient does not fit in a u128.
+fn mul_div_u128(a: u128, b: u128, c: u128) -> u128 {
+    assert!(c > 0, "mul_div_u128: division by zero");
+    let (high, low) = widening_mul_u128(a, b);
+    if high == 0 {
+        return low / c;
+    }
+    assert!(high < c, "mul_div_u128: quotient does not fit in a u128");
+
+    // Long-divide the 256-bit (high, low) value by `c`, one bit at a time.
+    // The high half only ever contributes to the remainder carried into the
+    // low half: `high < c` guarantees the true quotient fits in a u128.
+    let mut remainder: u128 = 0;
+    for i in (0..128).rev() {
+        remainder = (remainder << 1) | ((high >> i) & 1);
+        if remainder >= c {
+            remainder -= c;
+        }
+    }
+    let mut quotient: u128 = 0;
+    for i in (0..128).rev() {
+        remainder = (remainder << 1) | ((low >> i) & 1);
+        if remainder >= c {
+            remainder -= c;
+            quotient |= 1 << i;
+        }
+    }
+    quotient
+}
+
+/// Ordinal rank of a severity, used both for comparisons and as the
+/// `severity_index` bucket key.
+fn severity_rank(severity: &OutlierSeverity) -> u8 {
+    match severity {
+        OutlierSeverity::Low => 0,
+        OutlierSeverity::Medium => 1,
+        OutlierSeverity::High => 2,
+        OutlierSeverity::Critical => 3,
+    }
+}
+
+/// The most severe outlier in a set, if any.
+fn highest_severity(outliers: &[SemanticOutlier]) -> Option<OutlierSeverity> {
+    outliers
+        .iter()
+        .max_by_key(|o| severity_rank(&o.severity))
+        .map(|o| o.severity.clone())
+}
+
+/// Unique storage prefix for the `Vector` backing one `severity_index` bucket.
+fn severity_index_prefix(bucket: u8) -> Vec<u8> {
+    format!("hri{}", bucket).into_bytes()
+}
+
+/// Buckets how far an outlier's max distance exceeded the threshold into a severity.
+fn severity_for_excess(excess: f64) -> OutlierSeverity {
+    if excess > 0.5 {
+        OutlierSeverity::Critical
+    } else if excess > 0.25 {
+        OutlierSeverity::High
+    } else if excess > 0.1 {
+        OutlierSeverity::Medium
+    } else {
+        OutlierSeverity::Low
+    }
 }
 
 // ============================================================================
@@ -144,13 +257,70 @@ pub struct AnalysisMetadata {
 pub struct SemanticGuardContract {
     /// Stored semantic analysis records
     pub analyses: UnorderedMap<String, SemanticGuardRecord>,
-    
+
     /// Global contract metadata
     pub contract_metadata: ContractMetadata,
+
+    /// Per-(analysis, account) stake positions, keyed by `stake_key(analysis_id, account_id)`
+    pub stake_positions: LookupMap<String, StakePosition>,
+
+    /// Running for/against stake totals per analysis, keyed by analysis id
+    pub stake_tallies: LookupMap<String, StakeTally>,
+
+    /// Accounts that have staked on an analysis, keyed by analysis id, so
+    /// `resolve_consensus` can pay out winners without scanning all stakes
+    pub stakers_by_analysis: LookupMap<String, Vec<AccountId>>,
+
+    /// NEP-145-style storage deposit balance and bytes used, keyed by account
+    pub storage_balances: LookupMap<AccountId, StorageAccount>,
+
+    /// Analysis ids in submission order, so recent-first queries can page
+    /// without materializing the whole collection. `delete_analysis` moves
+    /// the last id into the deleted id's slot, so after a deletion the order
+    /// is submission order only apart from that move.
+    pub analysis_ids: Vector<String>,
+
+    /// Position of an analysis id within `analysis_ids`, so `delete_analysis`
+    /// can prune it in O(1) via `swap_remove` instead of leaving a stale,
+    /// ever-growing index behind
+    pub analysis_index: LookupMap<String, u64>,
+
+    /// Analysis ids bucketed by their highest outlier severity (keyed by
+    /// `severity_rank`), so `get_high_risk_analyses` only walks the buckets at
+    /// or above the requested threshold instead of scanning every analysis
+    pub severity_index: LookupMap<u8, Vector<String>>,
+
+    /// Which severity bucket (and position within it) an analysis id was
+    /// indexed under, if any, so `delete_analysis` can prune it from
+    /// `severity_index` too
+    pub severity_index_position: LookupMap<String, (u8, u64)>,
+
+    /// Owner + target chain for cross-chain verdict publication
+    pub bridge_config: BridgeConfig,
+
+    /// Published verdict messages, keyed by their sequence number, so a
+    /// verifier can re-derive the attestation hash from `get_published_message`
+    pub published_messages: LookupMap<u64, Vec<u8>>,
+
+    /// Sequence number a given analysis was published under, if any, making
+    /// `publish_verdict` idempotent
+    pub published_sequence_by_analysis: LookupMap<String, u64>,
+
+    /// Next sequence number to assign to a published verdict
+    pub next_sequence: u64,
+
+    /// Analysis ids that have attracted stake but have not yet reached
+    /// consensus, so `get_open_disputes` can page without scanning every
+    /// stored analysis
+    pub open_dispute_ids: Vector<String>,
+
+    /// Position of an analysis id within `open_dispute_ids`, so
+    /// `resolve_consensus` can remove it in O(1) via `swap_remove`
+    pub open_dispute_index: LookupMap<String, u64>,
 }
 
-#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
-#[serde(crate = "near_sdk::serde")]
+#[near(serializers = [json, borsh])]
+#[derive(Clone, Debug)]
 pub struct ContractMetadata {
     pub version: String,
     pub total_analyses: u64,
@@ -158,6 +328,42 @@ pub struct ContractMetadata {
     pub consensus_threshold: f64,
 }
 
+/// A single account's stake backing a verdict on an analysis's integrity
+#[near(serializers = [json, borsh])]
+#[derive(Clone, Debug)]
+pub struct StakePosition {
+    pub account_id: AccountId,
+    pub analysis_id: String,
+    pub amount: u128,
+    /// `true` votes that the analysis is integral, `false` disputes it
+    pub verdict: bool,
+    pub timestamp: u64,
+}
+
+#[near(serializers = [borsh])]
+#[derive(Clone, Default)]
+pub struct StakeTally {
+    pub stake_for: u128,
+    pub stake_against: u128,
+}
+
+/// Per-account storage accounting: NEAR deposited to pay for bytes persisted
+/// on this account's behalf, and how many bytes are currently in use.
+#[near(serializers = [borsh])]
+#[derive(Clone, Default)]
+pub struct StorageAccount {
+    pub deposit: u128,
+    pub bytes_used: u64,
+}
+
+/// NEP-145-shaped view of an account's storage balance
+#[near(serializers = [json, borsh])]
+#[derive(Clone, Debug)]
+pub struct StorageBalance {
+    pub total: u128,
+    pub available: u128,
+}
+
 #[near_bindgen]
 impl SemanticGuardContract {
     #[init]
@@ -170,6 +376,23 @@ impl SemanticGuardContract {
                 total_staked: 0,
                 consensus_threshold: 0.75,
             },
+            stake_positions: LookupMap::new(b"s"),
+            stake_tallies: LookupMap::new(b"t"),
+            stakers_by_analysis: LookupMap::new(b"k"),
+            storage_balances: LookupMap::new(b"b"),
+            analysis_ids: Vector::new(b"i"),
+            analysis_index: LookupMap::new(b"j"),
+            severity_index: LookupMap::new(b"h"),
+            severity_index_position: LookupMap::new(b"g"),
+            bridge_config: BridgeConfig {
+                owner_id: env::predecessor_account_id(),
+                target_chain_id: 0,
+            },
+            published_messages: LookupMap::new(b"p"),
+            published_sequence_by_analysis: LookupMap::new(b"q"),
+            next_sequence: 0,
+            open_dispute_ids: Vector::new(b"d"),
+            open_dispute_index: LookupMap::new(b"e"),
         }
     }
 
@@ -186,47 +409,559 @@ impl SemanticGuardContract {
         semantic_analysis: SemanticAnalysisResult,
         metadata: AnalysisMetadata,
     ) -> String {
+        let submitter = env::predecessor_account_id();
+        let attached = env::attached_deposit().as_yoctonear();
         let analysis_id = self.generate_analysis_id(&query);
-        
+
         let record = SemanticGuardRecord {
             id: analysis_id.clone(),
             query,
             results,
             semantic_analysis,
-            submitter: env::predecessor_account_id(),
+            submitter: submitter.clone(),
             timestamp: env::block_timestamp(),
             block_height: env::block_index(),
             metadata,
         };
 
+        let mut account = self.storage_balances.get(&submitter).unwrap_or_default();
+
+        let storage_before = env::storage_usage();
         self.analyses.insert(&analysis_id, &record);
+        self.analysis_index.insert(&analysis_id, &self.analysis_ids.len());
+        self.analysis_ids.push(&analysis_id);
+        if let Some(severity) = highest_severity(&record.semantic_analysis.outliers) {
+            let bucket = severity_rank(&severity);
+            let mut bucket_ids = self
+                .severity_index
+                .get(&bucket)
+                .unwrap_or_else(|| Vector::new(severity_index_prefix(bucket)));
+            self.severity_index_position
+                .insert(&analysis_id, &(bucket, bucket_ids.len()));
+            bucket_ids.push(&analysis_id);
+            self.severity_index.insert(&bucket, &bucket_ids);
+        }
         self.contract_metadata.total_analyses += 1;
+        let storage_after = env::storage_usage();
+
+        let bytes_added = storage_after.saturating_sub(storage_before);
+        let cost = bytes_added as u128 * env::storage_byte_cost().as_yoctonear();
+
+        // Pay for this submission from the attached deposit first; only draw on the
+        // account's pre-existing ledger balance if the attached amount falls short, and
+        // only bank what's actually needed — never credit the ledger with `attached` and
+        // then separately refund the surplus, or the same NEAR gets counted twice.
+        if attached >= cost {
+            let surplus = attached - cost;
+            if surplus > 0 {
+                Promise::new(submitter.clone()).transfer(NearToken::from_yoctonear(surplus));
+            }
+        } else {
+            let shortfall = cost - attached;
+            assert!(
+                account.deposit >= shortfall,
+                "Insufficient storage deposit: attach more NEAR or call storage_deposit() first"
+            );
+            account.deposit -= shortfall;
+        }
+        account.bytes_used += bytes_added;
+        self.storage_balances.insert(&submitter, &account);
 
         env::log_str(&format!("Semantic analysis stored: {}", analysis_id));
         analysis_id
     }
 
+    /// Submitters may remove their own analysis, crediting the freed storage
+    /// bytes back to their deposit balance.
+    pub fn delete_analysis(&mut self, analysis_id: String) -> bool {
+        let record = match self.analyses.get(&analysis_id) {
+            Some(record) => record,
+            None => return false,
+        };
+        assert_eq!(
+            env::predecessor_account_id(),
+            record.submitter,
+            "Only the submitter may delete their analysis"
+        );
+
+        let storage_before = env::storage_usage();
+        self.analyses.remove(&analysis_id);
+        let storage_after = env::storage_usage();
+        let bytes_freed = storage_before.saturating_sub(storage_after);
+
+        let mut account = self.storage_balances.get(&record.submitter).unwrap_or_default();
+        account.bytes_used = account.bytes_used.saturating_sub(bytes_freed);
+        account.deposit += bytes_freed as u128 * env::storage_byte_cost().as_yoctonear();
+        self.storage_balances.insert(&record.submitter, &account);
+
+        self.remove_analysis_id(&analysis_id);
+        self.remove_severity_index_entry(&analysis_id);
+
+        self.contract_metadata.total_analyses = self.contract_metadata.total_analyses.saturating_sub(1);
+        true
+    }
+
+    // ========================================================================
+    // NEP-145 STORAGE MANAGEMENT
+    // ========================================================================
+
+    /// Deposit NEAR to pay for the storage this account's records use.
+    /// Attach the amount to deposit; surplus beyond what is needed stays
+    /// banked for future submissions until withdrawn.
+    #[payable]
+    pub fn storage_deposit(&mut self, account_id: Option<AccountId>) -> StorageBalance {
+        let target = account_id.unwrap_or_else(env::predecessor_account_id);
+        let amount = env::attached_deposit().as_yoctonear();
+
+        let mut account = self.storage_balances.get(&target).unwrap_or_default();
+        account.deposit += amount;
+        self.storage_balances.insert(&target, &account);
+
+        self.storage_balance_of(target)
+    }
+
+    /// Withdraw up to the caller's unused storage deposit (deposit minus the
+    /// cost of bytes currently in use). Defaults to withdrawing everything available.
+    pub fn storage_withdraw(&mut self, amount: Option<u128>) -> StorageBalance {
+        let account_id = env::predecessor_account_id();
+        let mut account = self
+            .storage_balances
+            .get(&account_id)
+            .unwrap_or_else(|| env::panic_str("No storage balance for this account"));
+
+        let used_cost = account.bytes_used as u128 * env::storage_byte_cost().as_yoctonear();
+        let available = account.deposit.saturating_sub(used_cost);
+        let withdraw_amount = amount.unwrap_or(available);
+        assert!(
+            withdraw_amount <= available,
+            "Cannot withdraw more than the available storage balance"
+        );
+
+        account.deposit -= withdraw_amount;
+        self.storage_balances.insert(&account_id, &account);
+
+        if withdraw_amount > 0 {
+            Promise::new(account_id.clone()).transfer(NearToken::from_yoctonear(withdraw_amount));
+        }
+
+        self.storage_balance_of(account_id)
+    }
+
+    /// Current storage balance for an account: total deposited and what
+    /// remains available after the cost of bytes already in use.
+    pub fn storage_balance_of(&self, account_id: AccountId) -> StorageBalance {
+        let account = self.storage_balances.get(&account_id).unwrap_or_default();
+        let used_cost = account.bytes_used as u128 * env::storage_byte_cost().as_yoctonear();
+        StorageBalance {
+            total: account.deposit,
+            available: account.deposit.saturating_sub(used_cost),
+        }
+    }
+
     /// Retrieve semantic analysis by ID
     pub fn get_semantic_analysis(&self, analysis_id: String) -> Option<SemanticGuardRecord> {
         self.analyses.get(&analysis_id)
     }
 
-    /// Get analyses with outliers above threshold
-    pub fn get_high_risk_analyses(&self, severity_threshold: OutlierSeverity) -> Vec<SemanticGuardRecord> {
-        self.analyses
-            .values()
-            .filter(|record| {
-                record.semantic_analysis.outliers.iter().any(|outlier| {
-                    matches!(
-                        (&outlier.severity, &severity_threshold),
-                        (OutlierSeverity::Critical, _) |
-                        (OutlierSeverity::High, OutlierSeverity::High | OutlierSeverity::Medium | OutlierSeverity::Low) |
-                        (OutlierSeverity::Medium, OutlierSeverity::Medium | OutlierSeverity::Low) |
-                        (OutlierSeverity::Low, OutlierSeverity::Low)
-                    )
-                })
+    /// Page over analyses whose worst outlier meets or exceeds `severity_threshold`,
+    /// reading only the pre-bucketed severity index rather than scanning every
+    /// stored analysis.
+    pub fn get_high_risk_analyses(
+        &self,
+        severity_threshold: OutlierSeverity,
+        from_index: u64,
+        limit: u64,
+    ) -> Page<SemanticGuardRecord> {
+        let threshold_rank = severity_rank(&severity_threshold);
+        let buckets: Vec<u8> = (threshold_rank..=severity_rank(&OutlierSeverity::Critical)).rev().collect();
+
+        let mut ids: Vec<String> = Vec::new();
+        for bucket in &buckets {
+            if let Some(bucket_ids) = self.severity_index.get(bucket) {
+                for i in 0..bucket_ids.len() {
+                    if let Some(id) = bucket_ids.get(i) {
+                        ids.push(id);
+                    }
+                }
+            }
+        }
+
+        let total = ids.len() as u64;
+        let end = (from_index + limit).min(total);
+        let items = if from_index < end {
+            ids[from_index as usize..end as usize]
+                .iter()
+                .filter_map(|id| self.analyses.get(id))
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        Page {
+            items,
+            next_index: end,
+            total,
+        }
+    }
+
+    // ========================================================================
+    // ON-CHAIN RE-VERIFICATION
+    // ========================================================================
+
+    /// Recompute the semantic geometry of a stored analysis from its
+    /// `MessageEmbedding.vector` values and reconcile the result against what
+    /// the submitter originally reported. Flips every `SemanticOutlier`'s
+    /// `verification_status` from `Pending` to `Verified`/`Flagged`, and
+    /// flags any outlier the contract finds that the submitter omitted. A
+    /// confirmed outlier's `outlier_distances` records its recomputed
+    /// `threshold_exceeded_by` delta, so downstream consumers get a
+    /// structured value instead of having to parse `mismatches`.
+    pub fn verify_analysis(&mut self, analysis_id: String) -> VerificationReport {
+        let mut record = self
+            .analyses
+            .get(&analysis_id)
+            .unwrap_or_else(|| env::panic_str("Analysis not found"));
+
+        let ids: Vec<String> = record.results.iter().map(|r| r.id.clone()).collect();
+        let embeddings = &record.semantic_analysis.embeddings;
+        assert_eq!(
+            ids.len(),
+            embeddings.len(),
+            "results/embeddings length mismatch, cannot verify"
+        );
+        assert!(
+            embeddings.len() <= MAX_VERIFICATION_VECTORS,
+            "too many embeddings to verify in one call, gas would be exhausted"
+        );
+
+        let mut mismatches: Vec<String> = Vec::new();
+
+        // Rebuild the pairwise cosine-distance matrix.
+        let mut distance_matrix: HashMap<String, f64> = HashMap::new();
+        let mut max_distance: Vec<f64> = vec![0.0; ids.len()];
+        let mut max_distance_partner: Vec<Option<String>> = vec![None; ids.len()];
+        let mut sum_distance: Vec<f64> = vec![0.0; ids.len()];
+        for i in 0..ids.len() {
+            for j in (i + 1)..ids.len() {
+                let d = cosine_distance(&embeddings[i].vector, &embeddings[j].vector);
+                distance_matrix.insert(distance_matrix_key(&ids[i], &ids[j]), d);
+                sum_distance[i] += d;
+                sum_distance[j] += d;
+                if d > max_distance[i] {
+                    max_distance[i] = d;
+                    max_distance_partner[i] = Some(ids[j].clone());
+                }
+                if d > max_distance[j] {
+                    max_distance[j] = d;
+                    max_distance_partner[j] = Some(ids[i].clone());
+                }
+            }
+        }
+
+        // Center of gravity is the medoid: the id minimizing total distance to all others.
+        let recomputed_center_of_gravity = ids
+            .iter()
+            .enumerate()
+            .min_by(|(a_idx, _), (b_idx, _)| {
+                sum_distance[*a_idx]
+                    .partial_cmp(&sum_distance[*b_idx])
+                    .unwrap()
             })
-            .collect()
+            .map(|(_, id)| id.clone())
+            .unwrap_or_default();
+
+        let center_of_gravity_changed =
+            recomputed_center_of_gravity != record.semantic_analysis.center_of_gravity;
+        if center_of_gravity_changed {
+            mismatches.push(format!(
+                "center_of_gravity: submitted '{}' but recomputed '{}'",
+                record.semantic_analysis.center_of_gravity, recomputed_center_of_gravity
+            ));
+        }
+
+        // A result is an outlier when its max distance to any other result exceeds the threshold.
+        let threshold_used = record.semantic_analysis.threshold_used;
+        let recomputed_max: HashMap<&str, (f64, Option<&str>)> = ids
+            .iter()
+            .enumerate()
+            .map(|(i, id)| (id.as_str(), (max_distance[i], max_distance_partner[i].as_deref())))
+            .collect();
+
+        let mut verified_count = 0u32;
+        let mut flagged_count = 0u32;
+
+        for outlier in record.semantic_analysis.outliers.iter_mut() {
+            match recomputed_max.get(outlier.result_id.as_str()) {
+                Some(&(max_d, partner)) if max_d > threshold_used => {
+                    outlier.verification_status = VerificationStatus::Verified;
+                    outlier.max_distance = max_d;
+                    outlier.outlier_distances = vec![OutlierDistance {
+                        to_result: partner.unwrap_or_default().to_string(),
+                        distance: max_d,
+                        threshold_exceeded_by: max_d - threshold_used,
+                    }];
+                    verified_count += 1;
+                }
+                Some(&(max_d, _)) => {
+                    outlier.verification_status = VerificationStatus::Flagged;
+                    outlier.outlier_distances = vec![];
+                    flagged_count += 1;
+                    mismatches.push(format!(
+                        "{}: submitted as outlier but max_distance {:.6} does not exceed threshold {:.6}",
+                        outlier.result_id, max_d, threshold_used
+                    ));
+                }
+                None => {
+                    outlier.verification_status = VerificationStatus::Flagged;
+                    outlier.outlier_distances = vec![];
+                    flagged_count += 1;
+                    mismatches.push(format!(
+                        "{}: submitted as outlier but not present among recomputed results",
+                        outlier.result_id
+                    ));
+                }
+            }
+        }
+
+        // Results the submitter omitted that the contract independently flags as outliers.
+        let already_reported: std::collections::HashSet<String> = record
+            .semantic_analysis
+            .outliers
+            .iter()
+            .map(|o| o.result_id.clone())
+            .collect();
+
+        let mut newly_flagged: Vec<String> = Vec::new();
+        let mut new_outliers: Vec<SemanticOutlier> = Vec::new();
+        for (i, id) in ids.iter().enumerate() {
+            let max_d = max_distance[i];
+            if max_d > threshold_used && !already_reported.contains(id) {
+                mismatches.push(format!(
+                    "{}: omitted by submitter but max_distance {:.6} exceeds threshold {:.6}",
+                    id, max_d, threshold_used
+                ));
+                new_outliers.push(SemanticOutlier {
+                    result_id: id.clone(),
+                    reason: "Recomputed on-chain: distance exceeds threshold".to_string(),
+                    severity: severity_for_excess(max_d - threshold_used),
+                    max_distance: max_d,
+                    source_type: "on_chain_verification".to_string(),
+                    verification_status: VerificationStatus::Flagged,
+                    outlier_distances: vec![OutlierDistance {
+                        to_result: max_distance_partner[i].clone().unwrap_or_default(),
+                        distance: max_d,
+                        threshold_exceeded_by: max_d - threshold_used,
+                    }],
+                });
+                newly_flagged.push(id.clone());
+                flagged_count += 1;
+            }
+        }
+        record.semantic_analysis.outliers.extend(new_outliers);
+
+        let num_results = ids.len() as f64;
+        let num_outliers = record.semantic_analysis.outliers.len() as f64;
+        let consensus_score = if num_results > 0.0 {
+            1.0 - (num_outliers / num_results)
+        } else {
+            1.0
+        };
+
+        record.semantic_analysis.distance_matrix = distance_matrix;
+        record.semantic_analysis.center_of_gravity = recomputed_center_of_gravity.clone();
+        record.semantic_analysis.consensus_score = consensus_score;
+
+        self.analyses.insert(&analysis_id, &record);
+
+        env::log_str(&format!(
+            "Verified analysis {}: {} verified, {} flagged",
+            analysis_id, verified_count, flagged_count
+        ));
+
+        VerificationReport {
+            analysis_id,
+            verified_count,
+            flagged_count,
+            newly_flagged,
+            recomputed_center_of_gravity,
+            center_of_gravity_changed,
+            consensus_score,
+            mismatches,
+        }
+    }
+
+    // ========================================================================
+    // STAKING-BACKED VERIFICATION & DISPUTE RESOLUTION
+    // ========================================================================
+
+    /// Stake NEAR behind a verdict on whether an analysis's integrity holds up.
+    /// `verdict = true` backs the analysis, `verdict = false` disputes it.
+    /// Each account may stake once per analysis.
+    #[payable]
+    pub fn stake_verification(&mut self, analysis_id: String, verdict: bool) {
+        let record = self
+            .analyses
+            .get(&analysis_id)
+            .unwrap_or_else(|| env::panic_str("Analysis not found"));
+        assert!(
+            !record.metadata.consensus_reached,
+            "Consensus already reached for this analysis, staking is closed"
+        );
+
+        let amount = env::attached_deposit().as_yoctonear();
+        assert!(amount > 0, "Must attach a stake to vote");
+
+        let account_id = env::predecessor_account_id();
+        let key = stake_key(&analysis_id, &account_id);
+        assert!(
+            self.stake_positions.get(&key).is_none(),
+            "Account has already staked on this analysis"
+        );
+
+        self.stake_positions.insert(
+            &key,
+            &StakePosition {
+                account_id: account_id.clone(),
+                analysis_id: analysis_id.clone(),
+                amount,
+                verdict,
+                timestamp: env::block_timestamp(),
+            },
+        );
+
+        let is_first_stake = self.stake_tallies.get(&analysis_id).is_none();
+        let mut tally = self.stake_tallies.get(&analysis_id).unwrap_or_default();
+        if verdict {
+            tally.stake_for += amount;
+        } else {
+            tally.stake_against += amount;
+        }
+        self.stake_tallies.insert(&analysis_id, &tally);
+
+        if is_first_stake {
+            let index = self.open_dispute_ids.len();
+            self.open_dispute_ids.push(&analysis_id);
+            self.open_dispute_index.insert(&analysis_id, &index);
+        }
+
+        let mut stakers = self
+            .stakers_by_analysis
+            .get(&analysis_id)
+            .unwrap_or_default();
+        stakers.push(account_id.clone());
+        self.stakers_by_analysis.insert(&analysis_id, &stakers);
+
+        self.contract_metadata.total_staked += amount;
+
+        env::log_str(&format!(
+            "{} staked {} yoctoNEAR voting {} on analysis {}",
+            account_id, amount, verdict, analysis_id
+        ));
+    }
+
+    /// Tally stake-weighted votes for an analysis. Once the winning side's
+    /// fraction of total staked exceeds `consensus_threshold`, marks consensus
+    /// reached, updates the verification/dispute counters, and slashes the
+    /// losing side's stake to the winners pro-rata. Returns whether consensus
+    /// was reached by this call.
+    pub fn resolve_consensus(&mut self, analysis_id: String) -> bool {
+        let mut record = self
+            .analyses
+            .get(&analysis_id)
+            .unwrap_or_else(|| env::panic_str("Analysis not found"));
+        assert!(
+            !record.metadata.consensus_reached,
+            "Consensus already reached for this analysis"
+        );
+
+        let tally = self.stake_tallies.get(&analysis_id).unwrap_or_default();
+        let total_staked = tally.stake_for + tally.stake_against;
+        assert!(total_staked > 0, "No stake has been placed on this analysis");
+
+        let for_fraction = tally.stake_for as f64 / total_staked as f64;
+        let against_fraction = tally.stake_against as f64 / total_staked as f64;
+        let verdict_passed = for_fraction >= against_fraction;
+        let winning_fraction = if verdict_passed {
+            for_fraction
+        } else {
+            against_fraction
+        };
+
+        if winning_fraction <= self.contract_metadata.consensus_threshold {
+            return false;
+        }
+
+        record.metadata.consensus_reached = true;
+        if verdict_passed {
+            record.metadata.verification_count += 1;
+        } else {
+            record.metadata.dispute_count += 1;
+        }
+        self.analyses.insert(&analysis_id, &record);
+        self.remove_open_dispute(&analysis_id);
+
+        let (winning_pool, losing_pool) = if verdict_passed {
+            (tally.stake_for, tally.stake_against)
+        } else {
+            (tally.stake_against, tally.stake_for)
+        };
+
+        if winning_pool > 0 {
+            let stakers = self
+                .stakers_by_analysis
+                .get(&analysis_id)
+                .unwrap_or_default();
+            for account_id in stakers.iter() {
+                let key = stake_key(&analysis_id, account_id);
+                if let Some(position) = self.stake_positions.get(&key) {
+                    if position.verdict == verdict_passed {
+                        let slash_share = mul_div_u128(position.amount, losing_pool, winning_pool);
+                        let payout = position.amount + slash_share;
+                        Promise::new(account_id.clone()).transfer(NearToken::from_yoctonear(payout));
+                    }
+                }
+            }
+        }
+
+        env::log_str(&format!(
+            "Consensus resolved for {}: verdict={} ({:.2}% of stake)",
+            analysis_id,
+            verdict_passed,
+            winning_fraction * 100.0
+        ));
+
+        true
+    }
+
+    /// Look up a single account's stake position on an analysis, if any.
+    pub fn get_stake_position(
+        &self,
+        analysis_id: String,
+        account_id: AccountId,
+    ) -> Option<StakePosition> {
+        self.stake_positions.get(&stake_key(&analysis_id, &account_id))
+    }
+
+    /// Page over analyses that have attracted stake but have not yet reached
+    /// consensus, reading only the dedicated `open_dispute_ids` index rather
+    /// than scanning every stored analysis.
+    pub fn get_open_disputes(&self, from_index: u64, limit: u64) -> Page<SemanticGuardRecord> {
+        let total = self.open_dispute_ids.len();
+        let end = (from_index + limit).min(total);
+        let items = if from_index < end {
+            (from_index..end)
+                .filter_map(|i| self.open_dispute_ids.get(i))
+                .filter_map(|id| self.analyses.get(&id))
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        Page {
+            items,
+            next_index: end,
+            total,
+        }
     }
 
     // ========================================================================
@@ -292,6 +1027,54 @@ impl SemanticGuardContract {
         near_sdk::bs58::encode(hash).into_string()
     }
 
+    /// Removes an analysis id from `open_dispute_ids` in O(1) via
+    /// `swap_remove`, fixing up the moved id's recorded index.
+    fn remove_open_dispute(&mut self, analysis_id: &str) {
+        if let Some(index) = self.open_dispute_index.remove(&analysis_id.to_string()) {
+            let last_index = self.open_dispute_ids.len() - 1;
+            self.open_dispute_ids.swap_remove(index);
+            if index != last_index {
+                if let Some(moved_id) = self.open_dispute_ids.get(index) {
+                    self.open_dispute_index.insert(&moved_id, &index);
+                }
+            }
+        }
+    }
+
+    /// Removes an analysis id from `analysis_ids` in O(1) via `swap_remove`,
+    /// fixing up the moved id's recorded index.
+    fn remove_analysis_id(&mut self, analysis_id: &str) {
+        if let Some(index) = self.analysis_index.remove(&analysis_id.to_string()) {
+            let last_index = self.analysis_ids.len() - 1;
+            self.analysis_ids.swap_remove(index);
+            if index != last_index {
+                if let Some(moved_id) = self.analysis_ids.get(index) {
+                    self.analysis_index.insert(&moved_id, &index);
+                }
+            }
+        }
+    }
+
+    /// Removes an analysis id from whichever `severity_index` bucket it was
+    /// indexed under, if any, fixing up the moved id's recorded position.
+    fn remove_severity_index_entry(&mut self, analysis_id: &str) {
+        if let Some((bucket, index)) = self
+            .severity_index_position
+            .remove(&analysis_id.to_string())
+        {
+            if let Some(mut bucket_ids) = self.severity_index.get(&bucket) {
+                let last_index = bucket_ids.len() - 1;
+                bucket_ids.swap_remove(index);
+                if index != last_index {
+                    if let Some(moved_id) = bucket_ids.get(index) {
+                        self.severity_index_position.insert(&moved_id, &(bucket, index));
+                    }
+                }
+                self.severity_index.insert(&bucket, &bucket_ids);
+            }
+        }
+    }
+
     // ========================================================================
     // PUBLIC QUERY METHODS
     // ========================================================================
@@ -304,14 +1087,98 @@ impl SemanticGuardContract {
         self.contract_metadata.total_analyses
     }
 
-    pub fn get_recent_analyses(&self, limit: u32) -> Vec<SemanticGuardRecord> {
-        self.analyses
-            .values()
-            .collect::<Vec<_>>()
-            .into_iter()
-            .rev()
-            .take(limit as usize)
-            .collect()
+    /// Page over analyses newest-first, backed by the insertion-ordered
+    /// `analysis_ids` index instead of collecting every stored record.
+    /// Once an analysis has been deleted, the id that was last is read from
+    /// the deleted id's position, not as the newest.
+    pub fn get_recent_analyses(&self, from_index: u64, limit: u64) -> Page<SemanticGuardRecord> {
+        let total = self.analysis_ids.len();
+        let mut items = Vec::new();
+        let mut i = from_index;
+        while i < total && (items.len() as u64) < limit {
+            // `from_index` counts back from the newest record.
+            let actual_index = total - 1 - i;
+            if let Some(id) = self.analysis_ids.get(actual_index) {
+                if let Some(record) = self.analyses.get(&id) {
+                    items.push(record);
+                }
+            }
+            i += 1;
+        }
+
+        Page {
+            items,
+            next_index: i,
+            total,
+        }
+    }
+
+    // ========================================================================
+    // CROSS-CHAIN VERDICT PUBLICATION
+    // ========================================================================
+
+    /// Register the chain id that published verdicts are addressed to.
+    /// Owner-only; the owner is whoever deployed the contract.
+    pub fn register_bridge_target(&mut self, target_chain_id: u32) {
+        assert_eq!(
+            env::predecessor_account_id(),
+            self.bridge_config.owner_id,
+            "Only the contract owner may register a bridge target"
+        );
+        self.bridge_config.target_chain_id = target_chain_id;
+    }
+
+    /// Publish a consensus-reached analysis's verdict as a compact, portable
+    /// byte message: analysis id, semantic hashes, center of gravity, overall
+    /// consensus score and a severity summary. Assigns a monotonically
+    /// increasing sequence number and emits the message through
+    /// `LogMessageEmitter` for an off-chain relayer/guardian to observe and
+    /// attest. Idempotent: re-publishing an already-published analysis
+    /// returns its existing sequence number instead of emitting again.
+    pub fn publish_verdict(&mut self, analysis_id: String) -> u64 {
+        if let Some(sequence) = self.published_sequence_by_analysis.get(&analysis_id) {
+            return sequence;
+        }
+
+        let record = self
+            .analyses
+            .get(&analysis_id)
+            .unwrap_or_else(|| env::panic_str("Analysis not found"));
+        assert!(
+            record.metadata.consensus_reached,
+            "Consensus has not been reached for this analysis"
+        );
+
+        let payload = VerdictPayload {
+            analysis_id: analysis_id.clone(),
+            semantic_hashes: record
+                .semantic_analysis
+                .embeddings
+                .iter()
+                .map(|e| e.semantic_hash.clone())
+                .collect(),
+            center_of_gravity: record.semantic_analysis.center_of_gravity.clone(),
+            consensus_score: record.semantic_analysis.consensus_score,
+            severity_summary: summarize_severity(&record.semantic_analysis.outliers),
+        };
+        let message = borsh::to_vec(&payload)
+            .unwrap_or_else(|_| env::panic_str("Failed to encode verdict payload"));
+
+        let sequence = self.next_sequence;
+        self.next_sequence += 1;
+        self.published_messages.insert(&sequence, &message);
+        self.published_sequence_by_analysis
+            .insert(&analysis_id, &sequence);
+
+        LogMessageEmitter.emit(sequence, self.bridge_config.target_chain_id, &message);
+
+        sequence
+    }
+
+    /// Raw bytes of a published verdict message, so an off-chain verifier can
+    /// re-derive the attestation hash.
+    pub fn get_published_message(&self, sequence: u64) -> Option<Vec<u8>> {
+        self.published_messages.get(&sequence)
     }
 }
 
@@ -336,7 +1203,8 @@ mod tests {
 
     #[test]
     fn test_semantic_analysis_storage() {
-        let context = get_context(accounts(1));
+        let mut context = get_context(accounts(1));
+        context.attached_deposit(NearToken::from_yoctonear(10_000_000_000_000_000_000_000)); // 0.01 NEAR, covers storage cost
         testing_env!(context.build());
 
         let mut contract = SemanticGuardContract::new();
@@ -382,6 +1250,181 @@ mod tests {
         assert_eq!(contract.contract_metadata.total_analyses, 1);
     }
 
+    #[test]
+    fn test_verify_analysis_flags_omitted_outlier() {
+        let mut context = get_context(accounts(1));
+        context.attached_deposit(NearToken::from_yoctonear(10_000_000_000_000_000_000_000)); // 0.01 NEAR, covers storage cost
+        testing_env!(context.build());
+
+        let mut contract = SemanticGuardContract::new();
+
+        let results = vec![
+            SearchResult {
+                id: "A".to_string(),
+                title: "Result A".to_string(),
+                snippet: "".to_string(),
+                url: "https://test.com/a".to_string(),
+                rank: 1,
+                source_type: "scientific".to_string(),
+                semantic_hash: "hashA".to_string(),
+                trustworthiness_score: 0.9,
+            },
+            SearchResult {
+                id: "B".to_string(),
+                title: "Result B".to_string(),
+                snippet: "".to_string(),
+                url: "https://test.com/b".to_string(),
+                rank: 2,
+                source_type: "scientific".to_string(),
+                semantic_hash: "hashB".to_string(),
+                trustworthiness_score: 0.9,
+            },
+        ];
+
+        let embeddings = vec![
+            MessageEmbedding {
+                vector: vec![1.0, 0.0],
+                model_name: "test".to_string(),
+                embedding_hash: "ehA".to_string(),
+                semantic_hash: "hashA".to_string(),
+                timestamp: 0,
+            },
+            MessageEmbedding {
+                vector: vec![0.0, 1.0],
+                model_name: "test".to_string(),
+                embedding_hash: "ehB".to_string(),
+                semantic_hash: "hashB".to_string(),
+                timestamp: 0,
+            },
+        ];
+
+        // Submitter claims no outliers, but A and B are orthogonal (cosine distance 1.0),
+        // which exceeds the 0.5 threshold used below.
+        let analysis = SemanticAnalysisResult {
+            center_of_gravity: "A".to_string(),
+            outliers: vec![],
+            distance_matrix: HashMap::new(),
+            embeddings,
+            threshold_used: 0.5,
+            processing_time_ms: 100,
+            consensus_score: 1.0,
+        };
+
+        let metadata = AnalysisMetadata {
+            model_version: "1.0".to_string(),
+            algorithm_version: "1.0".to_string(),
+            processing_node: "node1".to_string(),
+            verification_count: 0,
+            dispute_count: 0,
+            consensus_reached: false,
+        };
+
+        let analysis_id =
+            contract.submit_semantic_analysis("test query".to_string(), results, analysis, metadata);
+
+        let report = contract.verify_analysis(analysis_id.clone());
+
+        assert_eq!(report.flagged_count, 2);
+        assert_eq!(report.newly_flagged.len(), 2);
+        assert!(report.consensus_score < 1.0);
+
+        let record = contract.get_semantic_analysis(analysis_id).unwrap();
+        assert_eq!(record.semantic_analysis.outliers.len(), 2);
+    }
+
+    #[test]
+    fn test_stake_verification_and_resolve_consensus() {
+        let context = get_context(accounts(1));
+        testing_env!(context.build());
+
+        let mut contract = SemanticGuardContract::new();
+        let analysis_id = contract.submit_demo_analysis(
+            "semantic_guard".to_string(),
+            "stake_test".to_string(),
+            "{}".to_string(),
+        );
+        // submit_demo_analysis marks consensus_reached: true, which closes staking,
+        // so reopen the record before staking on it.
+        let mut record = contract.get_semantic_analysis(analysis_id.clone()).unwrap();
+        record.metadata.consensus_reached = false;
+        contract.analyses.insert(&analysis_id, &record);
+
+        let mut staking_context = get_context(accounts(2));
+        staking_context.attached_deposit(NearToken::from_yoctonear(300));
+        testing_env!(staking_context.build());
+        contract.stake_verification(analysis_id.clone(), true);
+
+        let mut disputing_context = get_context(accounts(3));
+        disputing_context.attached_deposit(NearToken::from_yoctonear(50));
+        testing_env!(disputing_context.build());
+        contract.stake_verification(analysis_id.clone(), false);
+
+        assert_eq!(
+            contract
+                .get_stake_position(analysis_id.clone(), accounts(2))
+                .unwrap()
+                .amount,
+            300
+        );
+        assert_eq!(contract.get_open_disputes(0, 10).total, 1);
+
+        let resolved = contract.resolve_consensus(analysis_id.clone());
+        assert!(resolved);
+
+        let record = contract.get_semantic_analysis(analysis_id.clone()).unwrap();
+        assert!(record.metadata.consensus_reached);
+        assert_eq!(record.metadata.verification_count, 1);
+        assert_eq!(contract.get_open_disputes(0, 10).total, 0);
+    }
+
+    #[test]
+    fn test_storage_deposit_charged_and_refunded_on_delete() {
+        let mut context = get_context(accounts(1));
+        context.attached_deposit(NearToken::from_yoctonear(10_000_000_000_000_000_000_000)); // 0.01 NEAR
+        testing_env!(context.build());
+
+        let mut contract = SemanticGuardContract::new();
+        let results = vec![SearchResult {
+            id: "A".to_string(),
+            title: "Test Result".to_string(),
+            snippet: "Test snippet".to_string(),
+            url: "https://test.com".to_string(),
+            rank: 1,
+            source_type: "scientific".to_string(),
+            semantic_hash: "hash123".to_string(),
+            trustworthiness_score: 0.9,
+        }];
+        let analysis = SemanticAnalysisResult {
+            center_of_gravity: "A".to_string(),
+            outliers: vec![],
+            distance_matrix: HashMap::new(),
+            embeddings: vec![],
+            threshold_used: 0.75,
+            processing_time_ms: 100,
+            consensus_score: 0.95,
+        };
+        let metadata = AnalysisMetadata {
+            model_version: "1.0".to_string(),
+            algorithm_version: "1.0".to_string(),
+            processing_node: "node1".to_string(),
+            verification_count: 0,
+            dispute_count: 0,
+            consensus_reached: false,
+        };
+
+        let analysis_id =
+            contract.submit_semantic_analysis("test query".to_string(), results, analysis, metadata);
+
+        let balance_after_submit = contract.storage_balance_of(accounts(1));
+        assert!(balance_after_submit.total < 10_000_000_000_000_000_000_000);
+
+        assert!(contract.delete_analysis(analysis_id.clone()));
+        assert!(contract.get_semantic_analysis(analysis_id).is_none());
+
+        let balance_after_delete = contract.storage_balance_of(accounts(1));
+        assert!(balance_after_delete.available > balance_after_submit.available);
+    }
+
     #[test]
     fn test_demo_functionality() {
         let context = get_context(accounts(1));
@@ -399,4 +1442,87 @@ mod tests {
         assert!(contract.health_check());
         assert_eq!(contract.get_total_analyses(), 1);
     }
+
+    #[test]
+    fn test_paginated_recent_and_high_risk_analyses() {
+        let mut context = get_context(accounts(1));
+        context.attached_deposit(NearToken::from_yoctonear(10_000_000_000_000_000_000_000)); // 0.01 NEAR
+        testing_env!(context.build());
+
+        let mut contract = SemanticGuardContract::new();
+
+        let make_outliers = |severity: OutlierSeverity| {
+            vec![SemanticOutlier {
+                result_id: "A".to_string(),
+                reason: "test".to_string(),
+                severity,
+                max_distance: 0.9,
+                source_type: "scientific".to_string(),
+                verification_status: VerificationStatus::Pending,
+                outlier_distances: vec![],
+            }]
+        };
+
+        for (query, severity) in [
+            ("low risk query", OutlierSeverity::Low),
+            ("high risk query", OutlierSeverity::High),
+            ("critical risk query", OutlierSeverity::Critical),
+        ] {
+            let analysis = SemanticAnalysisResult {
+                center_of_gravity: "A".to_string(),
+                outliers: make_outliers(severity),
+                distance_matrix: HashMap::new(),
+                embeddings: vec![],
+                threshold_used: 0.75,
+                processing_time_ms: 100,
+                consensus_score: 0.5,
+            };
+            let metadata = AnalysisMetadata {
+                model_version: "1.0".to_string(),
+                algorithm_version: "1.0".to_string(),
+                processing_node: "node1".to_string(),
+                verification_count: 0,
+                dispute_count: 0,
+                consensus_reached: false,
+            };
+            contract.submit_semantic_analysis(query.to_string(), vec![], analysis, metadata);
+        }
+
+        let recent = contract.get_recent_analyses(0, 2);
+        assert_eq!(recent.total, 3);
+        assert_eq!(recent.items.len(), 2);
+        assert_eq!(recent.next_index, 2);
+        assert_eq!(recent.items[0].query, "critical risk query");
+
+        let high_risk = contract.get_high_risk_analyses(OutlierSeverity::High, 0, 10);
+        assert_eq!(high_risk.total, 2);
+        assert_eq!(high_risk.items.len(), 2);
+
+        let critical_only = contract.get_high_risk_analyses(OutlierSeverity::Critical, 0, 10);
+        assert_eq!(critical_only.total, 1);
+        assert_eq!(critical_only.items[0].query, "critical risk query");
+    }
+
+    #[test]
+    fn test_publish_verdict_is_idempotent() {
+        let context = get_context(accounts(1));
+        testing_env!(context.build());
+
+        let mut contract = SemanticGuardContract::new();
+        // submit_demo_analysis marks consensus_reached: true, so it's publishable.
+        let analysis_id = contract.submit_demo_analysis(
+            "semantic_guard".to_string(),
+            "publish_test".to_string(),
+            "{}".to_string(),
+        );
+
+        let sequence = contract.publish_verdict(analysis_id.clone());
+        assert_eq!(sequence, 0);
+        assert!(contract.get_published_message(sequence).is_some());
+
+        // Publishing the same analysis again returns the same sequence, not a new one.
+        let sequence_again = contract.publish_verdict(analysis_id);
+        assert_eq!(sequence, sequence_again);
+        assert_eq!(contract.next_sequence, 1);
+    }
 }
\ No newline at end of file